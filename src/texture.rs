@@ -10,6 +10,11 @@
 use std::ops::Deref;
 use {extensions, image, json, Gltf};
 
+#[cfg(feature = "import")]
+use data_uri;
+#[cfg(feature = "import")]
+use std::fmt;
+
 pub use json::texture::{MagFilter, MinFilter, WrappingMode};
 
 lazy_static! {
@@ -91,11 +96,31 @@ impl<'a> Sampler<'a> {
         self.json.mag_filter.map(|filter| filter.unwrap())
     }
 
+    /// Magnification filter, falling back to the conventional runtime
+    /// default of `Linear` when left unspecified, per the glTF spec.
+    pub fn mag_filter_or_default(&self) -> MagFilter {
+        self.mag_filter().unwrap_or(MagFilter::Linear)
+    }
+
     /// Minification filter.
     pub fn min_filter(&self) -> Option<MinFilter> {
         self.json.min_filter.map(|filter| filter.unwrap())
     }
 
+    /// Minification filter, falling back to the conventional runtime
+    /// default of `LinearMipmapLinear` when left unspecified, per the
+    /// glTF spec.
+    pub fn min_filter_or_default(&self) -> MinFilter {
+        self.min_filter().unwrap_or(MinFilter::LinearMipmapLinear)
+    }
+
+    /// Returns `true` if this is the synthesized default sampler returned
+    /// in place of an unset `Texture::sampler()`, as constructed by
+    /// `Sampler::default`.
+    pub fn is_default(&self) -> bool {
+        self.index.is_none()
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
@@ -169,6 +194,54 @@ impl<'a> Texture<'a> {
         self.gltf.images().nth(self.json.source.value() as usize).unwrap()
     }
 
+    /// Resolves and decodes this texture's image source into RGBA8 pixel
+    /// data, whatever its origin: an external URI, an embedded `data:`
+    /// URI, or a `bufferView` referencing GLB-packed PNG/JPEG data.
+    ///
+    /// `buffer_data` must hold the resolved contents of every `Buffer` in
+    /// the asset, indexed as by `Buffer::index()`, as produced by
+    /// `import::import_buffers`. `base` is the directory external URIs
+    /// are resolved against and is ignored by data and buffer-view
+    /// sources.
+    #[cfg(feature = "import")]
+    pub fn decoded_image(
+        &self,
+        base: Option<&::std::path::Path>,
+        buffer_data: &[Vec<u8>],
+    ) -> Result<Data, Error> {
+        let encoded = self.resolve_encoded_image(base, buffer_data)?;
+        let decoded = ::image::load_from_memory(&encoded)?.to_rgba();
+        let (width, height) = decoded.dimensions();
+        Ok(Data {
+            width: width,
+            height: height,
+            format: Format::R8G8B8A8,
+            pixels: decoded.into_raw(),
+        })
+    }
+
+    /// Locates the raw, still-encoded bytes of this texture's image
+    /// source, without decoding them.
+    #[cfg(feature = "import")]
+    fn resolve_encoded_image(
+        &self,
+        base: Option<&::std::path::Path>,
+        buffer_data: &[Vec<u8>],
+    ) -> Result<Vec<u8>, Error> {
+        let image = self.source();
+        if let Some(uri) = image.uri() {
+            if let Some(encoded) = data_uri::parse(uri) {
+                Ok(encoded)
+            } else {
+                let path = base.map(|base| base.join(uri)).unwrap_or_else(|| uri.into());
+                ::std::fs::read(path).map_err(Error::Io)
+            }
+        } else {
+            let view = image.buffer_view().ok_or(Error::MissingImageSource)?;
+            read_buffer_view(buffer_data, view.buffer().index(), view.offset(), view.length())
+        }
+    }
+
     /// Extension specific data.
     pub fn extensions(&self) -> extensions::texture::Texture<'a> {
         extensions::texture::Texture::new(
@@ -183,6 +256,141 @@ impl<'a> Texture<'a> {
     }
 }
 
+/// Copies out the byte range `[offset, offset + length)` of the buffer at
+/// `buffer_index`, as referenced by a `bufferView`-backed image source.
+///
+/// Returns `Error::MissingImageSource` rather than panicking when the
+/// buffer index or range is out of bounds, since the range comes straight
+/// from externally-sourced glTF/GLB data.
+#[cfg(feature = "import")]
+fn read_buffer_view(
+    buffer_data: &[Vec<u8>],
+    buffer_index: usize,
+    offset: usize,
+    length: usize,
+) -> Result<Vec<u8>, Error> {
+    let buffer = buffer_data.get(buffer_index).ok_or(Error::MissingImageSource)?;
+    let end = offset.checked_add(length).ok_or(Error::MissingImageSource)?;
+    buffer
+        .get(offset..end)
+        .map(|slice| slice.to_vec())
+        .ok_or(Error::MissingImageSource)
+}
+
+/// Decoded pixel data for a texture's image source, produced by
+/// `Texture::decoded_image`.
+#[cfg(feature = "import")]
+#[derive(Clone, Debug)]
+pub struct Data {
+    /// The width of the image in pixels.
+    width: u32,
+
+    /// The height of the image in pixels.
+    height: u32,
+
+    /// The layout of `pixels`.
+    format: Format,
+
+    /// The raw pixel data, `width * height * format.bytes_per_pixel()`
+    /// bytes in row-major order starting at the top-left corner.
+    pixels: Vec<u8>,
+}
+
+#[cfg(feature = "import")]
+impl Data {
+    /// The width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The layout of `pixels()`.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// The raw pixel data, `width() * height() * format().bytes_per_pixel()`
+    /// bytes in row-major order starting at the top-left corner.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+}
+
+/// The layout of pixel data returned by `Texture::decoded_image`.
+#[cfg(feature = "import")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// 8-bit red, green, blue and alpha channels.
+    R8G8B8A8,
+}
+
+#[cfg(feature = "import")]
+impl Format {
+    /// The number of bytes occupied by a single pixel in this format.
+    pub fn bytes_per_pixel(self) -> u32 {
+        match self {
+            Format::R8G8B8A8 => 4,
+        }
+    }
+}
+
+/// Error encountered while resolving or decoding a texture's image source.
+#[cfg(feature = "import")]
+#[derive(Debug)]
+pub enum Error {
+    /// Standard I/O error.
+    Io(::std::io::Error),
+
+    /// Image decoding error.
+    Image(::image::ImageError),
+
+    /// The image source could not be located, e.g. a `bufferView` index
+    /// referenced a non-existent `Buffer`.
+    MissingImageSource,
+}
+
+#[cfg(feature = "import")]
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref e) => e.fmt(f),
+            Error::Image(ref e) => e.fmt(f),
+            Error::MissingImageSource => {
+                write!(f, "the texture's image source could not be located")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "import")]
+impl ::std::error::Error for Error {
+    fn cause(&self) -> Option<&::std::error::Error> {
+        match *self {
+            Error::Io(ref e) => Some(e),
+            Error::Image(ref e) => Some(e),
+            Error::MissingImageSource => None,
+        }
+    }
+}
+
+#[cfg(feature = "import")]
+impl From<::std::io::Error> for Error {
+    fn from(err: ::std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+#[cfg(feature = "import")]
+impl From<::image::ImageError> for Error {
+    fn from(err: ::image::ImageError) -> Self {
+        Error::Image(err)
+    }
+}
+
 impl<'a> Info<'a> {
     /// Constructs a reference to a `Texture`.
     pub fn new(texture: Texture<'a>, json: &'a json::texture::Info) -> Self {
@@ -202,6 +410,16 @@ impl<'a> Info<'a> {
         self.json.tex_coord
     }
 
+    /// The `KHR_texture_transform` UV transform applied to this texture
+    /// reference, or the identity transform when the extension is not
+    /// present.
+    #[cfg(feature = "KHR_texture_transform")]
+    pub fn transform(&self) -> Transform {
+        self.extensions()
+            .texture_transform()
+            .unwrap_or_else(Transform::identity)
+    }
+
     /// Extension specific data.
     pub fn extensions(&self) -> extensions::texture::Info<'a> {
         extensions::texture::Info::new(
@@ -216,9 +434,144 @@ impl<'a> Info<'a> {
     }
 }
 
+/// The `KHR_texture_transform` extension data.
+///
+/// Describes an affine UV transform that should be applied to the texture
+/// coordinates referenced by an [`Info`] before sampling.
+#[cfg(feature = "KHR_texture_transform")]
+#[derive(Clone, Debug)]
+pub struct Transform {
+    /// The offset of the UV coordinate origin as a factor of the texture
+    /// dimensions.
+    offset: [f32; 2],
+
+    /// Rotation of the UV coordinates in radians, clockwise around the
+    /// origin.
+    rotation: f32,
+
+    /// The scale factor applied to the components of the UV coordinates.
+    scale: [f32; 2],
+
+    /// Overrides the `TEXCOORD` set index given by the referencing
+    /// [`Info`], if present.
+    tex_coord: Option<u32>,
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+impl Transform {
+    /// The identity transform, used when `KHR_texture_transform` is not
+    /// present on a texture reference.
+    pub(crate) fn identity() -> Self {
+        Self {
+            offset: [0.0, 0.0],
+            rotation: 0.0,
+            scale: [1.0, 1.0],
+            tex_coord: None,
+        }
+    }
+
+    /// The offset of the UV coordinate origin as a factor of the texture
+    /// dimensions.
+    pub fn offset(&self) -> [f32; 2] {
+        self.offset
+    }
+
+    /// Rotation of the UV coordinates in radians, clockwise around the
+    /// origin.
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// The scale factor applied to the components of the UV coordinates.
+    pub fn scale(&self) -> [f32; 2] {
+        self.scale
+    }
+
+    /// The `TEXCOORD` set index that overrides the one given by the
+    /// referencing [`Info`], taking precedence over `Info::tex_coord()`
+    /// when present.
+    pub fn tex_coord(&self) -> Option<u32> {
+        self.tex_coord
+    }
+
+    /// Composes `offset`, `rotation` and `scale` into a single 3x3
+    /// row-major matrix `M = T * R * S`, to be applied to a homogeneous
+    /// column vector `[u, v, 1]` as `M * [u, v, 1]`.
+    ///
+    /// Returns the identity matrix when constructed via
+    /// [`Transform::identity`].
+    pub fn texcoord_matrix(&self) -> [[f32; 3]; 3] {
+        let [tx, ty] = self.offset;
+        let [sx, sy] = self.scale;
+        let (sin_r, cos_r) = self.rotation.sin_cos();
+        [
+            [sx * cos_r, sy * sin_r, tx],
+            [-sx * sin_r, sy * cos_r, ty],
+            [0.0, 0.0, 1.0],
+        ]
+    }
+}
+
 impl<'a> Deref for Info<'a> {
     type Target = Texture<'a>;
     fn deref(&self) -> &Self::Target {
         &self.texture
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "KHR_texture_transform")]
+    #[test]
+    fn texcoord_matrix_identity() {
+        let identity = Transform::identity().texcoord_matrix();
+        assert_eq!(
+            identity,
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        );
+    }
+
+    #[cfg(feature = "KHR_texture_transform")]
+    #[test]
+    fn texcoord_matrix_offset_rotation_scale() {
+        use std::f32::consts::FRAC_PI_2;
+        let transform = Transform {
+            offset: [1.0, 2.0],
+            rotation: FRAC_PI_2,
+            scale: [3.0, 4.0],
+            tex_coord: None,
+        };
+        let matrix = transform.texcoord_matrix();
+        let expected = [[0.0, 4.0, 1.0], [-3.0, 0.0, 2.0], [0.0, 0.0, 1.0]];
+        for (row, expected_row) in matrix.iter().zip(expected.iter()) {
+            for (value, expected_value) in row.iter().zip(expected_row.iter()) {
+                assert!(
+                    (value - expected_value).abs() < 1e-6,
+                    "expected {:?}, got {:?}", expected, matrix,
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "import")]
+    #[test]
+    fn read_buffer_view_out_of_range_does_not_panic() {
+        let buffer_data = vec![vec![0u8; 8]];
+        match read_buffer_view(&buffer_data, 0, 4, 16) {
+            Err(Error::MissingImageSource) => {},
+            other => panic!("expected Err(Error::MissingImageSource), got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "import")]
+    #[test]
+    fn read_buffer_view_missing_buffer_does_not_panic() {
+        let buffer_data: Vec<Vec<u8>> = vec![];
+        match read_buffer_view(&buffer_data, 0, 0, 1) {
+            Err(Error::MissingImageSource) => {},
+            other => panic!("expected Err(Error::MissingImageSource), got {:?}", other),
+        }
+    }
+}